@@ -0,0 +1,160 @@
+//! This module contains `StrBuf`, a fixed-capacity, stack-allocated buffer implementing `Write`.
+//!
+//! Unlike `&mut [u8]`, which is consumed as it's written to, `StrBuf` keeps track of how much was
+//! written, so it can hand back the formatted string without the caller doing any bookkeeping.
+
+use core::str;
+
+use BufferOverflow;
+use Write;
+
+/// A fixed-capacity buffer of `N` bytes that implements `Write`.
+///
+/// This is useful for `no_std` users who want an owned, length-tracking destination for
+/// `fwrite!` without depending on `alloc`.
+pub struct StrBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StrBuf<N> {
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        StrBuf {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the written prefix of the buffer as a `str`.
+    pub fn as_str(&self) -> &str {
+        // Sound because `write_char`/`write_str` only ever append valid UTF-8.
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Returns the written prefix of the buffer as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Empties the buffer without touching its capacity.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns how many more bytes can still be written.
+    pub fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns the total capacity of the buffer.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns true if no more bytes can be written.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+}
+
+impl<const N: usize> Default for StrBuf<N> {
+    fn default() -> Self {
+        StrBuf::new()
+    }
+}
+
+impl<const N: usize> ::core::fmt::Debug for StrBuf<N> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        f.debug_struct("StrBuf").field("content", &self.as_str()).finish()
+    }
+}
+
+impl<const N: usize> Write for StrBuf<N> {
+    type Error = BufferOverflow;
+
+    fn write_char(&mut self, val: char) -> Result<(), Self::Error> {
+        let mut tmp = [0; 4];
+        let s = val.encode_utf8(&mut tmp);
+        self.write_str(s)
+    }
+
+    fn write_str(&mut self, val: &str) -> Result<(), Self::Error> {
+        let bytes = val.as_bytes();
+        if bytes.len() > self.remaining() {
+            return Err(BufferOverflow);
+        }
+
+        self.buf[self.len..(self.len + bytes.len())].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    // `uses_size_hint` stays at its default `false`: reporting `true` here would also make
+    // `fwrite!` (not just `fwrite_exact!`) feed this writer `Fmt::size_hint`'s loose upper bound
+    // (e.g. a flat 20 bytes for any `u64`, regardless of the value's actual digit count) through
+    // `size_hint`, and there'd be no way to tell that call apart from `fwrite_exact!`'s exact one
+    // without breaking `fwrite!` for every `StrBuf`. See `fwrite_exact!`'s doc comment.
+    fn size_hint(&mut self, _bytes: usize) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StrBuf;
+    use Write;
+    use BufferOverflow;
+
+    const TEST_STR: &str = "Hello world1";
+
+    #[test]
+    fn write_and_as_str() {
+        let mut buf: StrBuf<42> = StrBuf::new();
+        buf.write_str(TEST_STR).unwrap();
+        assert_eq!(buf.as_str(), TEST_STR);
+        assert_eq!(buf.as_bytes(), TEST_STR.as_bytes());
+    }
+
+    #[test]
+    fn overflow() {
+        let mut buf: StrBuf<5> = StrBuf::new();
+        assert_eq!(buf.write_str(TEST_STR), Err(BufferOverflow));
+    }
+
+    #[test]
+    fn clear_and_capacity() {
+        let mut buf: StrBuf<8> = StrBuf::new();
+        assert_eq!(buf.capacity(), 8);
+        assert_eq!(buf.remaining(), 8);
+        assert!(!buf.is_full());
+
+        buf.write_str("abcd").unwrap();
+        assert_eq!(buf.remaining(), 4);
+
+        buf.clear();
+        assert_eq!(buf.as_str(), "");
+        assert_eq!(buf.remaining(), 8);
+    }
+
+    #[test]
+    fn fwrite_exact_writes_chain_that_fits() {
+        let mut buf: StrBuf<8> = StrBuf::new();
+        fwrite_exact!(&mut buf, "ab", "cd").unwrap();
+        assert_eq!(buf.as_str(), "abcd");
+    }
+
+    #[test]
+    fn fwrite_exact_fails_on_chain_that_does_not_fit() {
+        let mut buf: StrBuf<4> = StrBuf::new();
+        assert_eq!(fwrite_exact!(&mut buf, "ab", "cdef"), Err(BufferOverflow));
+    }
+
+    #[test]
+    fn fwrite_accepts_values_whose_size_hint_overestimates() {
+        // `u64`'s `Fmt::size_hint` for `Display` is a flat 20 bytes regardless of the actual
+        // value, so a small `StrBuf` must still accept a small number through plain `fwrite!`
+        // instead of being tripped up by that loose upper bound.
+        let mut buf: StrBuf<10> = StrBuf::new();
+        fwrite!(&mut buf, 5u64).unwrap();
+        assert_eq!(buf.as_str(), "5");
+    }
+}
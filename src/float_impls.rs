@@ -0,0 +1,175 @@
+use core::str;
+
+use int_impls::write_digits;
+use Fmt;
+use Write;
+
+// `10u64.pow(19)` still fits in a `u64`; `10u64.pow(20)` doesn't. Beyond 19 fractional digits
+// there's no more precision an `f64` can actually carry, so we saturate by padding with zeros
+// instead of rejecting the strategy outright.
+const MAX_SCALE_DIGITS: usize = 19;
+
+/// Formats a float with a fixed number of digits after the decimal point.
+#[derive(Debug, Copy, Clone)]
+pub struct Fixed {
+    pub precision: usize,
+}
+
+impl Fixed {
+    /// Creates a new fixed-precision strategy.
+    pub fn new(precision: usize) -> Self {
+        Fixed { precision }
+    }
+}
+
+fn pow10_u64(exp: usize) -> u64 {
+    let mut result = 1u64;
+    for _ in 0..exp {
+        result = result.saturating_mul(10);
+    }
+    result
+}
+
+fn fmt_fixed<W: Write>(value: f64, strategy: &Fixed, writer: &mut W) -> Result<(), W::Error> {
+    if value.is_nan() {
+        return writer.write_str("NaN");
+    }
+
+    if value.is_infinite() {
+        return writer.write_str(if value.is_sign_negative() { "-inf" } else { "inf" });
+    }
+
+    if value.is_sign_negative() {
+        writer.write_char('-')?;
+    }
+
+    let value = value.abs();
+    let mut integer_part = value.trunc() as u128;
+
+    let effective_precision = strategy.precision.min(MAX_SCALE_DIGITS);
+    let scale = pow10_u64(effective_precision);
+    let mut scaled = (value.fract() * scale as f64).round() as u64;
+
+    if scaled >= scale {
+        integer_part += 1;
+        scaled = 0;
+    }
+
+    write_digits(integer_part, 10, false, writer)?;
+
+    if strategy.precision > 0 {
+        writer.write_char('.')?;
+
+        let mut buffer = [b'0'; MAX_SCALE_DIGITS];
+        let mut i = effective_precision;
+        let mut n = scaled;
+        while i > 0 {
+            i -= 1;
+            buffer[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+        // Sound because every byte was just written as an ASCII digit.
+        let s = unsafe { str::from_utf8_unchecked(&buffer[..effective_precision]) };
+        writer.write_str(s)?;
+
+        for _ in effective_precision..strategy.precision {
+            writer.write_char('0')?;
+        }
+    }
+
+    Ok(())
+}
+
+impl Fmt<Fixed> for f64 {
+    fn fmt<W: Write>(&self, writer: &mut W, strategy: &Fixed) -> Result<(), W::Error> {
+        fmt_fixed(*self, strategy, writer)
+    }
+
+    fn size_hint(&self, strategy: &Fixed) -> usize {
+        // The integer part comes from `value.trunc() as u128` (a saturating cast), so for
+        // large-magnitude floats it can be as wide as `u128::MAX`: 39 decimal digits, not 20.
+        1 + 39 + 1 + strategy.precision
+    }
+}
+
+impl Fmt<Fixed> for f32 {
+    fn fmt<W: Write>(&self, writer: &mut W, strategy: &Fixed) -> Result<(), W::Error> {
+        fmt_fixed(*self as f64, strategy, writer)
+    }
+
+    fn size_hint(&self, strategy: &Fixed) -> usize {
+        // The integer part comes from `value.trunc() as u128` (a saturating cast), so for
+        // large-magnitude floats it can be as wide as `u128::MAX`: 39 decimal digits, not 20.
+        1 + 39 + 1 + strategy.precision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Fmt;
+    use super::Fixed;
+
+    fn fmt_fixed(value: f64, precision: usize, expected: &str) {
+        let strategy = Fixed::new(precision);
+        let mut buf = [0u8; 64];
+        let len = {
+            let mut writer: &mut [u8] = &mut buf;
+            let before = writer.len();
+            value.fmt(&mut writer, &strategy).unwrap();
+            before - writer.len()
+        };
+        assert_eq!(&buf[..len], expected.as_bytes());
+    }
+
+    #[test]
+    fn basic_rounding() {
+        fmt_fixed(3.14162, 2, "3.14");
+        fmt_fixed(3.145, 2, "3.15");
+    }
+
+    #[test]
+    fn zero_precision_drops_the_point() {
+        fmt_fixed(3.6, 0, "4");
+    }
+
+    #[test]
+    fn negative_values() {
+        fmt_fixed(-1.5, 1, "-1.5");
+    }
+
+    #[test]
+    fn rounding_carries_into_the_integer_part() {
+        fmt_fixed(0.999, 2, "1.00");
+        // `9.005`'s nearest `f64` sits fractionally *above* 9.005 (9.00500000000000078...), so it
+        // reliably rounds up; `9.995`'s nearest `f64` sits fractionally *below* 9.995 and would
+        // round down instead, making it the wrong value to pick for this case.
+        fmt_fixed(9.005, 2, "9.01");
+    }
+
+    #[test]
+    fn special_values() {
+        fmt_fixed(f64::NAN, 2, "NaN");
+        fmt_fixed(f64::INFINITY, 2, "inf");
+        fmt_fixed(f64::NEG_INFINITY, 2, "-inf");
+    }
+
+    #[test]
+    fn pads_with_trailing_zeros() {
+        fmt_fixed(1.5, 4, "1.5000");
+    }
+
+    #[test]
+    fn size_hint_bounds_saturated_large_magnitude_values() {
+        // `trunc() as u128` saturates, so the integer part of a huge float is `u128::MAX`: 39
+        // digits, not the 20 a plain `u64`-sized value would need.
+        let strategy = Fixed::new(0);
+        let mut buf = [0u8; 64];
+        let len = {
+            let mut writer: &mut [u8] = &mut buf;
+            let before = writer.len();
+            f64::MAX.fmt(&mut writer, &strategy).unwrap();
+            before - writer.len()
+        };
+        assert!(f64::MAX.size_hint(&strategy) >= len);
+    }
+}
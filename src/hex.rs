@@ -0,0 +1,97 @@
+//! This module contains the `HexDump` strategy for formatting byte slices as hexadecimal.
+
+use core::str;
+
+use Fmt;
+use Write;
+
+/// Formats a byte slice as a hex dump.
+///
+/// Each byte becomes two hex digits, cased according to `uppercase`, with `separator` optionally
+/// inserted between consecutive bytes.
+#[derive(Debug, Copy, Clone)]
+pub struct HexDump {
+    pub uppercase: bool,
+    pub separator: Option<char>,
+}
+
+impl HexDump {
+    /// Creates a new hex dump strategy.
+    pub fn new(uppercase: bool, separator: Option<char>) -> Self {
+        HexDump { uppercase, separator }
+    }
+}
+
+fn nibble_char(nibble: u8, uppercase: bool) -> u8 {
+    if nibble < 10 {
+        b'0' + nibble
+    } else if uppercase {
+        b'A' + (nibble - 10)
+    } else {
+        b'a' + (nibble - 10)
+    }
+}
+
+impl Fmt<HexDump> for [u8] {
+    fn fmt<W: Write>(&self, writer: &mut W, strategy: &HexDump) -> Result<(), W::Error> {
+        for (i, byte) in self.iter().enumerate() {
+            if i > 0 {
+                if let Some(separator) = strategy.separator {
+                    writer.write_char(separator)?;
+                }
+            }
+
+            let buf = [
+                nibble_char(byte >> 4, strategy.uppercase),
+                nibble_char(byte & 0x0f, strategy.uppercase),
+            ];
+            // Sound because both bytes are always ASCII hex digits.
+            let s = unsafe { str::from_utf8_unchecked(&buf) };
+            writer.write_str(s)?;
+        }
+        Ok(())
+    }
+
+    fn size_hint(&self, strategy: &HexDump) -> usize {
+        2 * self.len() + strategy.separator.is_some() as usize * self.len().saturating_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Fmt;
+    use super::HexDump;
+
+    fn fmt_hex(bytes: &[u8], uppercase: bool, separator: Option<char>, expected: &str) {
+        let strategy = HexDump::new(uppercase, separator);
+        let mut buf = [0u8; 64];
+        let len = {
+            let mut writer: &mut [u8] = &mut buf;
+            let before = writer.len();
+            bytes.fmt(&mut writer, &strategy).unwrap();
+            before - writer.len()
+        };
+        assert_eq!(&buf[..len], expected.as_bytes());
+        assert!(bytes.size_hint(&strategy) >= len);
+    }
+
+    #[test]
+    fn lowercase_no_separator() {
+        fmt_hex(&[0x00, 0xab, 0xff], false, None, "00abff");
+    }
+
+    #[test]
+    fn uppercase_with_separator() {
+        fmt_hex(&[0x00, 0xab, 0xff], true, Some(' '), "00 AB FF");
+    }
+
+    #[test]
+    fn empty_slice() {
+        fmt_hex(&[], false, Some(':'), "");
+    }
+
+    #[test]
+    fn single_byte_has_no_separator() {
+        fmt_hex(&[0x42], false, Some(':'), "42");
+    }
+}
@@ -11,7 +11,7 @@ macro_rules! impls {
                 fn fmt<W>(
                     &self,
                     writer: &mut W,
-                    _strategy: Display,
+                    _strategy: &Display,
                 ) -> Result<(), W::Error>
                 where
                     W: Write,
@@ -31,7 +31,7 @@ macro_rules! impls {
                     }
                 }
 
-                fn size_hint(&self, _strategy: Display) -> usize {
+                fn size_hint(&self, _strategy: &Display) -> usize {
                     $N
                 }
             }
@@ -49,3 +49,215 @@ impls! {
     (u32, 10),
     (u64, 20),
 }
+
+/// Formats integers in an arbitrary radix, with configurable case for digits above 9.
+///
+/// `base` must be between 2 and 36 inclusive; every digit is mapped to `0-9` then `a-z`/`A-Z`.
+#[derive(Debug, Copy, Clone)]
+pub struct Radix {
+    pub base: u8,
+    pub uppercase: bool,
+}
+
+impl Radix {
+    /// Creates a new radix strategy.
+    ///
+    /// Panics if `base` is outside `2..=36`.
+    pub fn new(base: u8, uppercase: bool) -> Self {
+        assert!((2..=36).contains(&base), "Radix base must be between 2 and 36");
+        Radix { base, uppercase }
+    }
+}
+
+fn digit_char(digit: u8, uppercase: bool) -> u8 {
+    if digit < 10 {
+        b'0' + digit
+    } else if uppercase {
+        b'A' + (digit - 10)
+    } else {
+        b'a' + (digit - 10)
+    }
+}
+
+/// Writes the digits of `mag` (which must not be negative) in `base`/`uppercase` into `writer`.
+///
+/// `base` is assumed to already be checked to lie within `2..=36`.
+pub(crate) fn write_digits<W: Write>(mut mag: u128, base: u8, uppercase: bool, writer: &mut W) -> Result<(), W::Error> {
+    if mag == 0 {
+        return writer.write_char('0');
+    }
+
+    let base = base as u128;
+    // 128 bits is always enough: the widest type we support is 128 bits, and base 2 needs the
+    // most digits of any supported base.
+    let mut buffer = [0u8; 128];
+    let mut i = buffer.len();
+
+    while mag > 0 {
+        let rem = (mag % base) as u8;
+        mag /= base;
+        i -= 1;
+        buffer[i] = digit_char(rem, uppercase);
+    }
+
+    unsafe {
+        let s = str::from_utf8_unchecked(&buffer[i..]);
+        writer.write_str(s)
+    }
+}
+
+// A bound on the number of digits needed to represent any value of `bits` bits in `base`.
+//
+// `log2(base) >= 1` for every supported base, so `bits` digits is always enough; we don't bother
+// computing the tighter `ceil(bits / log2(base))` since no_std has no access to `log2`.
+fn radix_digit_capacity(bits: usize) -> usize {
+    bits
+}
+
+macro_rules! radix_unsigned_impls {
+    ($($ix:ident),+,) => {
+        $(
+            impl Fmt<Radix> for $ix {
+                fn fmt<W: Write>(&self, writer: &mut W, strategy: &Radix) -> Result<(), W::Error> {
+                    write_digits(*self as u128, strategy.base, strategy.uppercase, writer)
+                }
+
+                fn size_hint(&self, _strategy: &Radix) -> usize {
+                    radix_digit_capacity(mem::size_of::<$ix>() * 8)
+                }
+            }
+        )+
+    }
+}
+
+macro_rules! radix_signed_impls {
+    ($($ix:ident),+,) => {
+        $(
+            impl Fmt<Radix> for $ix {
+                fn fmt<W: Write>(&self, writer: &mut W, strategy: &Radix) -> Result<(), W::Error> {
+                    if *self < 0 {
+                        writer.write_char('-')?;
+                    }
+                    write_digits(self.unsigned_abs() as u128, strategy.base, strategy.uppercase, writer)
+                }
+
+                fn size_hint(&self, _strategy: &Radix) -> usize {
+                    1 + radix_digit_capacity(mem::size_of::<$ix>() * 8)
+                }
+            }
+        )+
+    }
+}
+
+radix_unsigned_impls! {
+    u8,
+    u16,
+    u32,
+    u64,
+}
+
+radix_signed_impls! {
+    i8,
+    i16,
+    i32,
+    i64,
+}
+
+// `u128`/`i128` are implemented by hand rather than through the macros above: their magnitude is
+// already a `u128`, so going through `as u128`/`self.unsigned_abs() as u128` like the narrower
+// widths do would be a no-op cast (clippy's `unnecessary_cast`).
+impl Fmt<Radix> for u128 {
+    fn fmt<W: Write>(&self, writer: &mut W, strategy: &Radix) -> Result<(), W::Error> {
+        write_digits(*self, strategy.base, strategy.uppercase, writer)
+    }
+
+    fn size_hint(&self, _strategy: &Radix) -> usize {
+        radix_digit_capacity(mem::size_of::<u128>() * 8)
+    }
+}
+
+impl Fmt<Radix> for i128 {
+    fn fmt<W: Write>(&self, writer: &mut W, strategy: &Radix) -> Result<(), W::Error> {
+        if *self < 0 {
+            writer.write_char('-')?;
+        }
+        write_digits(self.unsigned_abs(), strategy.base, strategy.uppercase, writer)
+    }
+
+    fn size_hint(&self, _strategy: &Radix) -> usize {
+        1 + radix_digit_capacity(mem::size_of::<i128>() * 8)
+    }
+}
+
+impl Fmt<Display> for u128 {
+    fn fmt<W: Write>(&self, writer: &mut W, _strategy: &Display) -> Result<(), W::Error> {
+        write_digits(*self, 10, false, writer)
+    }
+
+    fn size_hint(&self, _strategy: &Display) -> usize {
+        39
+    }
+}
+
+impl Fmt<Display> for i128 {
+    fn fmt<W: Write>(&self, writer: &mut W, _strategy: &Display) -> Result<(), W::Error> {
+        if *self < 0 {
+            writer.write_char('-')?;
+        }
+        write_digits(self.unsigned_abs(), 10, false, writer)
+    }
+
+    fn size_hint(&self, _strategy: &Display) -> usize {
+        40
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {Fmt, Radix};
+
+    fn fmt_radix<T: Fmt<Radix>>(val: T, base: u8, uppercase: bool, expected: &str) {
+        let mut buf = [0u8; 48];
+        let radix = Radix::new(base, uppercase);
+        let len = {
+            let mut writer: &mut [u8] = &mut buf;
+            let before = writer.len();
+            val.fmt(&mut writer, &radix).unwrap();
+            before - writer.len()
+        };
+        assert_eq!(&buf[..len], expected.as_bytes());
+    }
+
+    #[test]
+    fn radix_unsigned() {
+        fmt_radix(255u32, 16, false, "ff");
+        fmt_radix(255u32, 16, true, "FF");
+        fmt_radix(5u8, 2, false, "101");
+        fmt_radix(0u64, 10, false, "0");
+    }
+
+    #[test]
+    fn radix_signed() {
+        fmt_radix(-255i32, 16, false, "-ff");
+        fmt_radix(5i8, 2, false, "101");
+        fmt_radix(0i64, 10, false, "0");
+    }
+
+    #[test]
+    fn radix_128_bit() {
+        fmt_radix(u128::MAX, 16, true, "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+        fmt_radix(i128::MIN, 10, false, "-170141183460469231731687303715884105728");
+    }
+
+    #[test]
+    fn radix_signed_min_does_not_overflow() {
+        // `i32::MIN`'s magnitude doesn't fit in `i32`; `unsigned_abs` (not `-self`) is required.
+        fmt_radix(i32::MIN, 10, false, "-2147483648");
+    }
+
+    #[test]
+    #[should_panic]
+    fn radix_rejects_base_out_of_range() {
+        Radix::new(1, false);
+    }
+}
@@ -18,6 +18,10 @@ extern crate numtoa;
 extern crate void;
 
 mod int_impls;
+pub use int_impls::Radix;
+
+mod float_impls;
+pub use float_impls::Fixed;
 
 #[cfg(feature = "with_std")]
 mod std_impls;
@@ -25,8 +29,12 @@ mod std_impls;
 #[macro_use]
 mod macros;
 
+pub mod buffer;
 pub mod consts;
+pub mod hex;
+pub mod padded;
 pub mod transform;
+pub mod writers;
 
 use transform::{Transform, Transformer, Transformed};
 
@@ -117,13 +125,26 @@ pub trait Fmt<S = Display> {
     /// If the implementor doesn't know maximum possible size, it should return minimum possible
     /// size. (0 is always valid minimum)
     fn size_hint(&self, strategy: &S) -> usize;
+}
 
-    /// Combinator for transforming the value,
-    fn transformed<T: Transform>(self, transformation: T) -> transform::Transformed<Self, T> where Self: Sized {
+/// Extension trait providing the `.transformed()` combinator.
+///
+/// This lives on its own trait, bounded on `Fmt<Display>` specifically, rather than as a default
+/// method on `Fmt<S>` itself. `S` is a trait parameter, not something that appears in
+/// `transformed`'s signature, so if it were a method of `Fmt<S>`, the compiler would have no way
+/// to pick which `S` a bare `.transformed()` call means as soon as `Self` implements `Fmt` for
+/// more than one strategy (e.g. both `Display` and `Radix`) - every such call becomes an `E0283`
+/// ambiguity error. Pinning the bound to `Fmt<Display>` here keeps `.transformed()` unambiguous no
+/// matter how many other strategies `Self` also supports.
+pub trait FmtExt: Fmt<Display> + Sized {
+    /// Combinator for transforming the value.
+    fn transformed<T: Transform>(self, transformation: T) -> transform::Transformed<Self, T> {
         Transformed::new(self, transformation)
     }
 }
 
+impl<V: Fmt<Display>> FmtExt for V {}
+
 impl<'a, S, T: ?Sized + Fmt<S>> Fmt<S> for &'a T {
     fn fmt<W: Write>(&self, writer: &mut W, strategy: &S) -> Result<(), W::Error> {
         (*self).fmt(writer, strategy)
@@ -367,6 +388,7 @@ mod tests {
     fn transform() {
         use ::transform::Transform;
         use ::Write;
+        use ::FmtExt;
 
         struct Upper;
 
@@ -27,3 +27,36 @@ macro_rules! fwrite {
         }
     };
 }
+
+/// Like `fwrite!`, but computes the exact formatted length up front instead of relying on
+/// `Fmt::size_hint`'s possibly-loose estimate.
+///
+/// When the writer's `uses_size_hint` returns `true`, this formats the arguments into a
+/// `CountWriter` first, so it does strictly more work than `fwrite!`; otherwise it skips the
+/// counting pass and the size hint entirely. Use it when the writer can make good use of a precise size (e.g. a single
+/// allocation for `String`). Whether this buys anything beyond that depends entirely on what the
+/// writer's `size_hint` does with the number: it's called before any of the real writes, but
+/// `Write::size_hint` returns `()`, so it has no way to reject the chain on the spot, and no
+/// writer in this crate (including `StrBuf`) uses the hint to do so after the fact either -
+/// `uses_size_hint`/`size_hint` is the same mechanism `fwrite!` uses with its looser estimate, so
+/// a writer can't tell the two calls apart without also changing `fwrite!`'s behavior for every
+/// other caller. A too-large chain still fails at the first write that doesn't fit, same as
+/// `fwrite!`, just without allocating or writing more than necessary to reach that point.
+#[macro_export]
+macro_rules! fwrite_exact {
+    ($writer:expr, $($args:expr),*) => {
+        {
+            use $crate::Fmt;
+            let chain = $crate::Empty;
+            $( let chain = chain.chain(fast_fmt_instantiate!($args)); )*
+
+            if $crate::Write::uses_size_hint($writer) {
+                let mut counter = $crate::writers::CountWriter::new();
+                let _ = chain.fmt(&mut counter, &$crate::consts::DISPLAY);
+                $crate::Write::size_hint($writer, counter.amount_written_bytes());
+            }
+
+            chain.fmt($writer, &$crate::consts::DISPLAY)
+        }
+    };
+}
@@ -25,6 +25,11 @@ pub trait Transform {
     /// It should always be maximum of possible scenarios. If maximum can't be determined, the
     /// `bytes` value should be returned unchanged.
     fn transform_size_hint(&self, bytes: usize) -> usize;
+
+    /// Combinator that feeds everything through `self` and then through `other`.
+    fn then<T: Transform>(self, other: T) -> Then<Self, T> where Self: Sized {
+        Then::new(self, other)
+    }
 }
 
 impl<'a, T: Transform> Transform for &'a T {
@@ -105,6 +110,95 @@ impl<'a, T: Transform, S, U: Fmt<S>> Fmt<TransformStrategy<'a, T, S>> for U {
 }
 */
 
+/// Combinator that runs one transformation, then feeds its output through a second one.
+pub struct Then<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: Transform, B: Transform> Then<A, B> {
+    /// Composes two transformations, `first` then `second`.
+    pub fn new(first: A, second: B) -> Self {
+        Then {
+            first,
+            second,
+        }
+    }
+}
+
+impl<A: Transform, B: Transform> Transform for Then<A, B> {
+    fn transform_char<W: Write>(&self, writer: &mut W, c: char) -> Result<(), W::Error> {
+        let mut writer = Transformer::new(&self.second, writer);
+        self.first.transform_char(&mut writer, c)
+    }
+
+    fn transform_str<W: Write>(&self, writer: &mut W, s: &str) -> Result<(), W::Error> {
+        let mut writer = Transformer::new(&self.second, writer);
+        self.first.transform_str(&mut writer, s)
+    }
+
+    fn transform_size_hint(&self, bytes: usize) -> usize {
+        self.second.transform_size_hint(self.first.transform_size_hint(bytes))
+    }
+}
+
+fn hex_digit(nibble: u8) -> u8 {
+    if nibble < 10 {
+        b'0' + nibble
+    } else {
+        b'A' + (nibble - 10)
+    }
+}
+
+fn is_unreserved(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_' || c == '~'
+}
+
+/// Percent-encodes text per RFC 3986, passing the unreserved characters `A-Za-z0-9-._~` through
+/// unchanged and encoding every other byte of a char's UTF-8 encoding as `%XX`.
+pub struct PercentEncode;
+
+impl Transform for PercentEncode {
+    fn transform_char<W: Write>(&self, writer: &mut W, c: char) -> Result<(), W::Error> {
+        if is_unreserved(c) {
+            return writer.write_char(c);
+        }
+
+        let mut buf = [0; 4];
+        for &byte in c.encode_utf8(&mut buf).as_bytes() {
+            let encoded = [b'%', hex_digit(byte >> 4), hex_digit(byte & 0x0f)];
+            // Sound because every byte is `%` or an uppercase hex digit.
+            let s = unsafe { ::core::str::from_utf8_unchecked(&encoded) };
+            writer.write_str(s)?;
+        }
+        Ok(())
+    }
+
+    fn transform_size_hint(&self, bytes: usize) -> usize {
+        bytes * 3
+    }
+}
+
+/// Escapes the characters that are special in HTML text content: `&`, `<`, `>`, `"` and `'`.
+pub struct HtmlEscape;
+
+impl Transform for HtmlEscape {
+    fn transform_char<W: Write>(&self, writer: &mut W, c: char) -> Result<(), W::Error> {
+        match c {
+            '&' => writer.write_str("&amp;"),
+            '<' => writer.write_str("&lt;"),
+            '>' => writer.write_str("&gt;"),
+            '"' => writer.write_str("&quot;"),
+            '\'' => writer.write_str("&#39;"),
+            _ => writer.write_char(c),
+        }
+    }
+
+    fn transform_size_hint(&self, bytes: usize) -> usize {
+        bytes * 6
+    }
+}
+
 /// Transformation attached to a value, transforming given vale when formatting.
 pub struct Transformed<V, T: Transform> {
     value: V,
@@ -131,3 +225,47 @@ impl<S, V: Fmt<S>, T: Transform> Fmt<S> for Transformed<V, T> {
         self.transformation.transform_size_hint(self.value.size_hint(strategy))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {Display, Fmt, FmtExt};
+    use super::{HtmlEscape, PercentEncode, Transform};
+
+    fn fmt_transformed<T: Transform>(value: &str, transformation: T, expected: &str) {
+        let mut buf = [0u8; 64];
+        let len = {
+            let mut writer: &mut [u8] = &mut buf;
+            let before = writer.len();
+            value.transformed(transformation).fmt(&mut writer, &Display).unwrap();
+            before - writer.len()
+        };
+        assert_eq!(&buf[..len], expected.as_bytes());
+    }
+
+    #[test]
+    fn percent_encode_passes_unreserved_through() {
+        fmt_transformed("abc-XYZ_0.9~", PercentEncode, "abc-XYZ_0.9~");
+    }
+
+    #[test]
+    fn percent_encode_escapes_reserved_and_multibyte() {
+        fmt_transformed("a b", PercentEncode, "a%20b");
+        fmt_transformed("\u{e9}", PercentEncode, "%C3%A9");
+    }
+
+    #[test]
+    fn html_escape_escapes_special_chars() {
+        fmt_transformed("<a href=\"x\">&'</a>", HtmlEscape, "&lt;a href=&quot;x&quot;&gt;&amp;&#39;&lt;/a&gt;");
+    }
+
+    #[test]
+    fn html_escape_passes_plain_text_through() {
+        fmt_transformed("just text", HtmlEscape, "just text");
+    }
+
+    #[test]
+    fn then_composes_two_transforms_in_order() {
+        fmt_transformed("a b", PercentEncode.then(HtmlEscape), "a%20b");
+        fmt_transformed("<a b>", HtmlEscape.then(PercentEncode), "%26lt%3Ba%20b%26gt%3B");
+    }
+}
@@ -0,0 +1,131 @@
+//! This module contains general-purpose `Write` combinators: `CountWriter`, which tracks how
+//! much would be written without needing a real destination, and `Tee`, which forwards writes to
+//! two destinations at once.
+
+use void::Void;
+
+use Write;
+
+/// A writer that never fails and only tracks how many chars and UTF-8 bytes were written to it.
+///
+/// Useful for finding out the exact size of a formatted value before committing it to a real
+/// writer, e.g. to get a precise `size_hint` instead of an estimate.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct CountWriter {
+    chars: usize,
+    bytes: usize,
+}
+
+impl CountWriter {
+    /// Creates a new, empty counter.
+    pub fn new() -> Self {
+        CountWriter {
+            chars: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Returns how many chars were written so far.
+    pub fn amount_written_chars(&self) -> usize {
+        self.chars
+    }
+
+    /// Returns how many UTF-8 bytes were written so far.
+    pub fn amount_written_bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+impl Write for CountWriter {
+    type Error = Void;
+
+    fn write_char(&mut self, val: char) -> Result<(), Self::Error> {
+        self.chars += 1;
+        self.bytes += val.len_utf8();
+        Ok(())
+    }
+
+    fn write_str(&mut self, val: &str) -> Result<(), Self::Error> {
+        self.chars += val.chars().count();
+        self.bytes += val.len();
+        Ok(())
+    }
+
+    fn size_hint(&mut self, _bytes: usize) {}
+}
+
+/// Forwards every write to both `A` and `B`, stopping at the first error.
+pub struct Tee<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: Write, B: Write<Error = A::Error>> Tee<A, B> {
+    /// Creates a writer that forwards everything written to it to both `first` and `second`.
+    pub fn new(first: A, second: B) -> Self {
+        Tee {
+            first,
+            second,
+        }
+    }
+}
+
+impl<A: Write, B: Write<Error = A::Error>> Write for Tee<A, B> {
+    type Error = A::Error;
+
+    fn write_char(&mut self, val: char) -> Result<(), Self::Error> {
+        self.first.write_char(val)?;
+        self.second.write_char(val)
+    }
+
+    fn write_str(&mut self, val: &str) -> Result<(), Self::Error> {
+        self.first.write_str(val)?;
+        self.second.write_str(val)
+    }
+
+    fn size_hint(&mut self, bytes: usize) {
+        self.first.size_hint(bytes);
+        self.second.size_hint(bytes);
+    }
+
+    fn uses_size_hint(&self) -> bool {
+        self.first.uses_size_hint() || self.second.uses_size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CountWriter, Tee};
+    use Write;
+
+    #[test]
+    fn count_writer_counts_chars_and_bytes() {
+        let mut counter = CountWriter::new();
+        counter.write_str("Hello ").unwrap();
+        counter.write_char('\u{1F600}').unwrap();
+        assert_eq!(counter.amount_written_chars(), 7);
+        assert_eq!(counter.amount_written_bytes(), "Hello ".len() + '\u{1F600}'.len_utf8());
+    }
+
+    #[test]
+    fn tee_forwards_to_both_writers() {
+        let mut a = [0u8; 16];
+        let mut b = [0u8; 16];
+        {
+            let mut tee = Tee::new(&mut a[..], &mut b[..]);
+            tee.write_str("hi").unwrap();
+        }
+        assert_eq!(&a[0..2], b"hi");
+        assert_eq!(&b[0..2], b"hi");
+    }
+
+    #[test]
+    fn tee_stops_at_first_error() {
+        use BufferOverflow;
+
+        let mut small = [0u8; 1];
+        let mut big = [0u8; 16];
+        let mut tee = Tee::new(&mut small[..], &mut big[..]);
+        assert_eq!(tee.write_str("hi"), Err(BufferOverflow));
+    }
+}